@@ -1,5 +1,8 @@
 //! Functions for operations on polynomials.
 
+use num::Complex;
+use num_traits::{One, Zero};
+
 fn plus_minus(val: f64, string: String) -> String {
   if val > 0.0 {
     format!(" + {string}")
@@ -50,51 +53,87 @@ fn inbounds<T>(index: usize, vec: &Vec<T>) -> bool {
   index < vec.len()
 }
 
-/// A struct that represents any polynomial.
+/// Evaluates a polynomial given by its coefficients at a complex `x`
+/// using Horner's method.
+fn complex_evaluate(data: &[f64], x: Complex<f64>) -> Complex<f64> {
+  let mut acc = Complex::new(0.0, 0.0);
+  for coeff in data.iter().rev() {
+    acc = acc * x + Complex::new(*coeff, 0.0);
+  }
+  acc
+}
+
+/// A struct that represents any polynomial, generic over its
+/// coefficient type `T`. Defaults to `f64` so existing code that just
+/// writes `GeneralPolynomial` keeps compiling unchanged.
 #[derive(Debug, Clone, PartialEq)]
-pub struct GeneralPolynomial {
-  data: Vec<f64>
+pub struct GeneralPolynomial<T = f64> {
+  data: Vec<T>
 }
 
-impl std::ops::Add<Self> for GeneralPolynomial {
+impl<T> std::ops::Add<Self> for GeneralPolynomial<T>
+where
+  T: std::ops::Add<Output = T> + Clone,
+{
   type Output = Self;
   fn add(self, other: Self) -> Self::Output {
     let size = larger(self.data.len(), other.data.len());
     let mut new = Vec::with_capacity(size);
-    let mut to_push = 0.0;
     for i in 0..size {
       if inbounds(i, &self.data) && inbounds(i, &other.data) {
-        to_push = self.data[i] + other.data[i];
+        new.push(self.data[i].clone() + other.data[i].clone());
+      }
+      else if inbounds(i, &self.data) && !inbounds(i, &other.data) {
+        new.push(self.data[i].clone());
       }
-      else if inbounds(i, &self.data) && !inbounds(i, &other.data) { 
-        to_push = self.data[i];
-      } 
       else if inbounds(i, &other.data) && !inbounds(i, &self.data) {
-        to_push = other.data[i];
+        new.push(other.data[i].clone());
+      }
+      else {
+        unreachable!("i < larger(self.data.len(), other.data.len()) is always inbounds for one side")
       }
-      new.push(to_push)
     }
     Self::new(new)
   }
 }
 
-impl std::ops::Sub<Self> for GeneralPolynomial {
+impl<T> std::ops::Sub<Self> for GeneralPolynomial<T>
+where
+  T: std::ops::Sub<Output = T> + Clone,
+{
   type Output = Self;
   fn sub(self, other: Self) -> Self::Output {
     let size = larger(self.data.len(), other.data.len());
     let mut new = Vec::with_capacity(size);
-    let mut to_push = 0.0;
     for i in 0..size {
       if inbounds(i, &self.data) && inbounds(i, &other.data) {
-        to_push = self.data[i] - other.data[i];
+        new.push(self.data[i].clone() - other.data[i].clone());
+      }
+      else if inbounds(i, &self.data) && !inbounds(i, &other.data) {
+        new.push(self.data[i].clone());
       }
-      else if inbounds(i, &self.data) && !inbounds(i, &other.data) { 
-        to_push = self.data[i];
-      } 
       else if inbounds(i, &other.data) && !inbounds(i, &self.data) {
-        to_push = other.data[i];
+        new.push(other.data[i].clone());
+      }
+      else {
+        unreachable!("i < larger(self.data.len(), other.data.len()) is always inbounds for one side")
+      }
+    }
+    Self::new(new)
+  }
+}
+
+impl<T> std::ops::Mul<Self> for GeneralPolynomial<T>
+where
+  T: std::ops::Mul<Output = T> + std::ops::Add<Output = T> + Zero + Clone,
+{
+  type Output = Self;
+  fn mul(self, other: Self) -> Self::Output {
+    let mut new = vec![T::zero(); self.data.len() + other.data.len() - 1];
+    for i in 0..self.data.len() {
+      for j in 0..other.data.len() {
+        new[i + j] = new[i + j].clone() + self.data[i].clone() * other.data[j].clone();
       }
-      new.push(to_push)
     }
     Self::new(new)
   }
@@ -102,20 +141,303 @@ impl std::ops::Sub<Self> for GeneralPolynomial {
 
 // fmt::Display for GeneralPolynomial coming in 0.2.2
 
-impl GeneralPolynomial {
+impl<T> GeneralPolynomial<T> {
   /// Creates a new GeneralPolynomial
-  pub fn new(data: Vec<f64>) -> Self {
+  pub fn new(data: Vec<T>) -> Self {
     Self { data }
   }
+}
 
+impl<T: From<i32>> From<Vec<i32>> for GeneralPolynomial<T> {
+  fn from(data: Vec<i32>) -> Self {
+    Self { data: data.into_iter().map(T::from).collect() }
+  }
+}
+
+impl<T: From<i32>> GeneralPolynomial<T> {
   /// Creates a new GeneralPolynomial from integers.
   pub fn new_i(data: Vec<i32>) -> Self {
-    let mut new = Vec::with_capacity(data.len());
-    for i in 0..data.len() {
-      new.push(data[i].into());
+    Self::from(data)
+  }
+}
+
+impl<T> GeneralPolynomial<T>
+where
+  T: Zero + PartialEq + Clone,
+{
+  /// Returns true if the polynomial is a zero polynomial,
+  /// false otherwise
+  pub fn is_zero(&self) -> bool {
+    self.data.iter().all(|c| *c == T::zero())
+  }
+
+  /// Returns the index of the highest-order nonzero coefficient,
+  /// ignoring trailing zeros.
+  pub fn degree(&self) -> u8 {
+    for i in (0..self.data.len()).rev() {
+      if self.data[i] != T::zero() {
+        return i as u8;
+      }
+    }
+    0
+  }
+
+  /// Drops trailing zero coefficients, so that `degree()` and
+  /// `PartialEq` behave as expected.
+  pub fn trimmed(mut self) -> Self {
+    while self.data.len() > 1 && *self.data.last().unwrap() == T::zero() {
+      self.data.pop();
+    }
+    self
+  }
+}
+
+impl<T> GeneralPolynomial<T>
+where
+  T: std::ops::Add<Output = T> + std::ops::Mul<Output = T> + Zero + Clone,
+{
+  /// Evaluates the polynomial for the given `x` using Horner's method.
+  pub fn evaluate(&self, x: T) -> T {
+    let mut acc = T::zero();
+    for coeff in self.data.iter().rev() {
+      acc = acc * x.clone() + coeff.clone();
+    }
+    acc
+  }
+}
+
+impl<T> GeneralPolynomial<T>
+where
+  T: std::ops::Add<Output = T> + std::ops::Mul<Output = T> + Zero + One + Clone,
+{
+  /// Returns the derivative of the polynomial.
+  pub fn derivative(&self) -> Self {
+    if self.data.len() <= 1 {
+      return Self::new(vec![T::zero()]);
+    }
+    let mut new = Vec::with_capacity(self.data.len() - 1);
+    let mut multiplier = T::one();
+    for i in 0..self.data.len() - 1 {
+      new.push(self.data[i + 1].clone() * multiplier.clone());
+      multiplier = multiplier + T::one();
+    }
+    Self::new(new)
+  }
+}
+
+impl<T> GeneralPolynomial<T>
+where
+  T: std::ops::Div<Output = T> + Zero + PartialEq + Clone,
+{
+  /// Divides every coefficient by the leading (highest-degree nonzero)
+  /// coefficient, producing a monic polynomial. Returns `None` for the
+  /// zero polynomial.
+  pub fn monic(mut self) -> Option<Self> {
+    let leading = self.data.iter().rev().find(|c| **c != T::zero())?.clone();
+    for coeff in self.data.iter_mut() {
+      *coeff = coeff.clone() / leading.clone();
+    }
+    Some(self)
+  }
+}
+
+impl<T> GeneralPolynomial<T>
+where
+  T: std::ops::Add<Output = T>
+    + std::ops::Sub<Output = T>
+    + std::ops::Mul<Output = T>
+    + std::ops::Div<Output = T>
+    + Zero
+    + PartialEq
+    + Clone,
+{
+  /// Divides `self` by `divisor`, returning `(quotient, remainder)` via
+  /// polynomial long division. Returns `None` if `divisor` is the zero
+  /// polynomial.
+  pub fn div_rem(self, divisor: &Self) -> Option<(Self, Self)> {
+    if divisor.is_zero() {
+      return None;
+    }
+    let divisor = divisor.clone().trimmed();
+    let div_degree = divisor.degree() as usize;
+    let div_leading = divisor.data[div_degree].clone();
+
+    let mut remainder = self.trimmed();
+    let mut quotient = vec![T::zero(); 1];
+
+    while !remainder.is_zero() && remainder.degree() as usize >= div_degree {
+      let rem_degree = remainder.degree() as usize;
+      let shift = rem_degree - div_degree;
+      let term = remainder.data[rem_degree].clone() / div_leading.clone();
+
+      if quotient.len() <= shift {
+        quotient.resize(shift + 1, T::zero());
+      }
+      quotient[shift] = quotient[shift].clone() + term.clone();
+
+      let mut subtrahend = vec![T::zero(); shift + div_degree + 1];
+      for (i, coeff) in divisor.data.iter().enumerate() {
+        subtrahend[shift + i] = subtrahend[shift + i].clone() + term.clone() * coeff.clone();
+      }
+      let new_remainder = (remainder - Self::new(subtrahend)).trimmed();
+
+      // `term` can lose precision (integer truncation, float underflow),
+      // so the leading coefficient isn't guaranteed to cancel exactly.
+      // If the degree didn't strictly drop, further iterations would just
+      // repeat this step forever, so stop rather than loop infinitely.
+      let stuck = !new_remainder.is_zero() && new_remainder.degree() as usize >= rem_degree;
+      remainder = new_remainder;
+      if stuck {
+        break;
+      }
+    }
+
+    Some((Self::new(quotient), remainder))
+  }
+}
+
+impl<T> GeneralPolynomial<T>
+where
+  T: std::ops::Add<Output = T>
+    + std::ops::Mul<Output = T>
+    + std::ops::Neg<Output = T>
+    + Zero
+    + One
+    + Clone,
+{
+  /// Constructs the polynomial with exactly the given roots, by
+  /// multiplying out `(x - r_1)(x - r_2)...(x - r_n)`.
+  pub fn from_roots(roots: &[T]) -> Self {
+    let mut poly = Self::new(vec![T::one()]);
+    for r in roots {
+      poly = poly * Self::new(vec![-r.clone(), T::one()]);
+    }
+    poly
+  }
+}
+
+impl GeneralPolynomial<f64> {
+  /// Sets any coefficient whose absolute value is below `epsilon`
+  /// to exactly `0.0`. Useful for cleaning up floating-point noise
+  /// after root finding or multiplication.
+  pub fn rounded(mut self, epsilon: f64) -> Self {
+    for coeff in self.data.iter_mut() {
+      if coeff.abs() < epsilon {
+        *coeff = 0.0;
+      }
     }
-    Self { data: new }
+    self
   }
+
+  /// Returns the L1 norm of the coefficients: the sum of their
+  /// absolute values.
+  pub fn norm_l1(&self) -> f64 {
+    self.data.iter().map(|c| c.abs()).sum()
+  }
+
+  /// Returns the L2 norm of the coefficients: the square root of the
+  /// sum of their squares.
+  pub fn norm_l2(&self) -> f64 {
+    self.data.iter().map(|c| c * c).sum::<f64>().sqrt()
+  }
+
+  /// Returns the L-infinity norm of the coefficients: the largest
+  /// absolute value among them.
+  pub fn norm_linf(&self) -> f64 {
+    self.data.iter().fold(0.0_f64, |max, c| larger(max, c.abs()))
+  }
+
+  /// Finds all complex roots of the polynomial via Durand–Kerner
+  /// (Weierstrass) simultaneous iteration.
+  pub fn roots(&self) -> Vec<Complex<f64>> {
+    let poly = self.clone().trimmed();
+    if poly.is_zero() || poly.degree() == 0 {
+      return Vec::new();
+    }
+    let poly = poly.monic().unwrap();
+    let n = poly.degree() as usize;
+
+    const TOLERANCE: f64 = 1e-12;
+    const MAX_ITERATIONS: usize = 1000;
+
+    let seed = Complex::new(0.4, 0.9);
+    let mut guesses = Vec::with_capacity(n);
+    let mut power = Complex::new(1.0, 0.0);
+    for _ in 0..n {
+      guesses.push(power);
+      power *= seed;
+    }
+
+    for _ in 0..MAX_ITERATIONS {
+      let snapshot = guesses.clone();
+      let mut max_delta = 0.0_f64;
+      for i in 0..n {
+        let mut denominator = Complex::new(1.0, 0.0);
+        for j in 0..n {
+          if i != j {
+            denominator *= snapshot[i] - snapshot[j];
+          }
+        }
+        let delta = complex_evaluate(&poly.data, snapshot[i]) / denominator;
+        guesses[i] -= delta;
+        max_delta = larger(max_delta, delta.norm());
+      }
+      if max_delta < TOLERANCE {
+        break;
+      }
+    }
+
+    guesses
+  }
+
+  /// Finds the real-root factorization of a cubic polynomial, with
+  /// floating-point (not exact) roots and coefficients:
+  /// finds one real root via [`Self::roots`] (every real cubic has at
+  /// least one), deflates by synthetic division via [`Self::div_rem`],
+  /// and classifies the remaining quadratic by its discriminant.
+  /// Returns `None` if `self` (after trimming trailing zeros) isn't
+  /// degree 3.
+  pub fn factor_cubic(&self) -> Option<Factors> {
+    let poly = self.clone().trimmed();
+    if poly.degree() != 3 {
+      return None;
+    }
+    let a = poly.data[3];
+
+    let x1 = poly.roots()
+      .into_iter()
+      .min_by(|r1, r2| r1.im.abs().partial_cmp(&r2.im.abs()).unwrap())?
+      .re;
+
+    let (quotient, _remainder) = poly.div_rem(&Self::new(vec![-x1, 1.0]))?;
+    let (d, b) = (quotient.data[0], quotient.data[1]);
+    let discriminant = b * b - 4.0 * a * d;
+
+    if discriminant >= 0.0 {
+      let sqrt_disc = discriminant.sqrt();
+      let x2 = (-b + sqrt_disc) / (2.0 * a);
+      let x3 = (-b - sqrt_disc) / (2.0 * a);
+      Some(Factors::ThreeLinear { a, x1, x2, x3 })
+    } else {
+      Some(Factors::LinearAndQuadratic { a, x1, b: b / a, c: d / a })
+    }
+  }
+}
+
+// Cubic::roots and Quartic::roots delegating through a GeneralPolynomial
+// conversion coming in 0.3.0
+
+/// The real-root factorization of a cubic polynomial, produced by
+/// [`GeneralPolynomial::<f64>::factor_cubic`]. (There's no standalone
+/// `Cubic` type in this tree to hang this off of as `Cubic::factor`, so
+/// it lives on `GeneralPolynomial<f64>` instead — the primitives it
+/// needs, `roots` and `div_rem`, already live there too.)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Factors {
+  /// `a(x - x1)(x - x2)(x - x3)`
+  ThreeLinear { a: f64, x1: f64, x2: f64, x3: f64 },
+  /// `a(x - x1)(x^2 + bx + c)`, where `x^2 + bx + c` has no real roots.
+  LinearAndQuadratic { a: f64, x1: f64, b: f64, c: f64 },
 }
 
 /// A trait providing methods for operations on polynomials.
@@ -135,6 +457,24 @@ pub trait Polynomial {
   fn derivative(&self) -> Self::Derivative;
 }
 
+/// A `Polynomial` that has a well-defined antiderivative.
+///
+/// Kept separate from `Polynomial` itself so that adding integration
+/// support doesn't force every existing `impl Polynomial` to grow one;
+/// only the types that opt in need `type Integral`/`integral()`.
+pub trait Integrable: Polynomial {
+  /// The type returned by the integral() function
+  type Integral: Polynomial;
+  /// Returns the antiderivative of the polynomial, using `constant` as
+  /// the constant of integration (the new `x^0` term).
+  fn integral(&self, constant: f64) -> Self::Integral;
+  /// Evaluates the definite integral of the polynomial from `a` to `b`.
+  fn definite_integral(&self, a: f64, b: f64) -> f64 {
+    let antiderivative = self.integral(0.0);
+    antiderivative.evaluate(b) - antiderivative.evaluate(a)
+  }
+}
+
 impl Polynomial for f64 {
   #[allow(unused_variables)]
   fn evaluate(&self, x: f64) -> f64 {
@@ -144,13 +484,63 @@ impl Polynomial for f64 {
     self.to_owned() == 0.0
   }
   fn degree(&self) -> u8 { 0 }
-  
+
   type Derivative = f64;
   fn derivative(&self) -> Self::Derivative {
     0.0
   }
 }
 
+impl Integrable for f64 {
+  type Integral = Linear;
+  fn integral(&self, constant: f64) -> Self::Integral {
+    Linear::new(*self, constant)
+  }
+}
+
+// evaluate/is_zero/degree/derivative are implemented generically as
+// inherent methods above, over whatever coefficient type `T` the bounds
+// allow; these trait methods just forward to them so GeneralPolynomial<f64>
+// can still be used as a `Polynomial` alongside Linear/Quadratic/Cubic/Quartic.
+impl Polynomial for GeneralPolynomial<f64> {
+  fn evaluate(&self, x: f64) -> f64 {
+    GeneralPolynomial::evaluate(self, x)
+  }
+
+  fn is_zero(&self) -> bool {
+    GeneralPolynomial::is_zero(self)
+  }
+
+  fn degree(&self) -> u8 {
+    GeneralPolynomial::degree(self)
+  }
+
+  type Derivative = GeneralPolynomial<f64>;
+  fn derivative(&self) -> Self::Derivative {
+    GeneralPolynomial::derivative(self)
+  }
+}
+
+// `integral` stays f64-only: its `constant` parameter and the `Polynomial`
+// bound on `Self::Integral` are pinned to f64 by the `Integrable` trait
+// itself (the same way `evaluate`'s `x: f64` is), so genericizing it would
+// mean redesigning `Integrable`, not just this impl.
+impl Integrable for GeneralPolynomial<f64> {
+  type Integral = GeneralPolynomial<f64>;
+  fn integral(&self, constant: f64) -> Self::Integral {
+    let mut new = Vec::with_capacity(self.data.len() + 1);
+    new.push(constant);
+    for (i, coeff) in self.data.iter().enumerate() {
+      new.push(coeff / (i as f64 + 1.0));
+    }
+    Self::new(new)
+  }
+}
+
+// Linear::Integral = Quadratic, Quadratic::Integral = Cubic, and so on
+// up the fixed-degree hierarchy (i.e. `impl Integrable for Linear`, etc.)
+// is coming in 0.3.0 alongside the rest of that module's
+// GeneralPolynomial-backed calculus support.
 mod linear;
 mod quadratic;
 mod cubic;
@@ -166,7 +556,7 @@ mod tests {
   use super::*;
 
   mod gp {
-    use super::GeneralPolynomial;
+    use super::{GeneralPolynomial, Integrable, Factors};
 
     #[test]
     fn add() {
@@ -175,10 +565,254 @@ mod tests {
       let gp3 = GeneralPolynomial::new(vec![-2.0, 1.0, -4.6, 20.0, 6.0]);
       assert_eq!(gp1 + gp2, gp3);
     }
+
+    #[test]
+    fn add_i64() {
+      let gp1: GeneralPolynomial<i64> = GeneralPolynomial::new(vec![1, 2, 3]);
+      let gp2: GeneralPolynomial<i64> = GeneralPolynomial::new(vec![4, 5]);
+      assert_eq!(gp1 + gp2, GeneralPolynomial::new(vec![5, 7, 3]));
+    }
+
+    #[test]
+    fn mul_i64() {
+      let gp1: GeneralPolynomial<i64> = GeneralPolynomial::new(vec![1, 1]);
+      let gp2: GeneralPolynomial<i64> = GeneralPolynomial::new(vec![-1, 1]);
+      assert_eq!(gp1 * gp2, GeneralPolynomial::new(vec![-1, 0, 1]));
+    }
+
+    #[test]
+    fn new_i_generic() {
+      let gp: GeneralPolynomial<i64> = GeneralPolynomial::new_i(vec![1, 2, 3]);
+      assert_eq!(gp, GeneralPolynomial::new(vec![1i64, 2, 3]));
+    }
+
+    #[test]
+    fn eval() {
+      let gp = GeneralPolynomial::new(vec![1.0, 2.0, 3.0]);
+      assert_eq!(gp.evaluate(2.0), 17.0);
+    }
+
+    #[test]
+    fn eval_i64() {
+      let gp: GeneralPolynomial<i64> = GeneralPolynomial::new(vec![1, 2, 3]);
+      assert_eq!(gp.evaluate(2), 17);
+    }
+
+    #[test]
+    fn is_zero() {
+      let gp = GeneralPolynomial::new(vec![0.0, 0.0, 0.0]);
+      let gp2 = GeneralPolynomial::new(vec![0.0, 1.0, 0.0]);
+      assert!(gp.is_zero());
+      assert!(!gp2.is_zero());
+    }
+
+    #[test]
+    fn degree() {
+      let gp = GeneralPolynomial::new(vec![1.0, 2.0, 3.0, 0.0, 0.0]);
+      assert_eq!(gp.degree(), 2);
+    }
+
+    #[test]
+    fn derive() {
+      let gp = GeneralPolynomial::new(vec![4.0, 3.0, 2.0, 1.0]);
+      assert_eq!(gp.derivative(), GeneralPolynomial::new(vec![3.0, 4.0, 3.0]));
+    }
+
+    #[test]
+    fn derive_i64() {
+      let gp: GeneralPolynomial<i64> = GeneralPolynomial::new(vec![4, 3, 2, 1]);
+      assert_eq!(gp.derivative(), GeneralPolynomial::new(vec![3, 4, 3]));
+    }
+
+    #[test]
+    fn trimmed() {
+      let gp = GeneralPolynomial::new(vec![1.0, 0.0, 0.0]);
+      assert_eq!(gp.trimmed(), GeneralPolynomial::new(vec![1.0]));
+    }
+
+    #[test]
+    fn rounded() {
+      let gp = GeneralPolynomial::new(vec![1.0, 1e-15, -1e-15, 2.0]);
+      assert_eq!(gp.rounded(1e-9), GeneralPolynomial::new(vec![1.0, 0.0, 0.0, 2.0]));
+    }
+
+    #[test]
+    fn monic() {
+      let gp = GeneralPolynomial::new(vec![4.0, 6.0, 2.0]);
+      assert_eq!(gp.monic().unwrap(), GeneralPolynomial::new(vec![2.0, 3.0, 1.0]));
+      assert!(GeneralPolynomial::new(vec![0.0, 0.0]).monic().is_none());
+    }
+
+    #[test]
+    fn monic_i64() {
+      let gp: GeneralPolynomial<i64> = GeneralPolynomial::new(vec![4, 6, 2]);
+      assert_eq!(gp.monic().unwrap(), GeneralPolynomial::new(vec![2, 3, 1]));
+    }
+
+    #[test]
+    fn norms() {
+      let gp = GeneralPolynomial::new(vec![3.0, -4.0]);
+      assert_eq!(gp.norm_l1(), 7.0);
+      assert_eq!(gp.norm_l2(), 5.0);
+      assert_eq!(gp.norm_linf(), 4.0);
+    }
+
+    #[test]
+    fn mul() {
+      let gp1 = GeneralPolynomial::new(vec![1.0, 1.0]);
+      let gp2 = GeneralPolynomial::new(vec![-1.0, 1.0]);
+      assert_eq!(gp1 * gp2, GeneralPolynomial::new(vec![-1.0, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn div_rem() {
+      // (x^2 - 1) / (x - 1) = x + 1 remainder 0
+      let dividend = GeneralPolynomial::new(vec![-1.0, 0.0, 1.0]);
+      let divisor = GeneralPolynomial::new(vec![-1.0, 1.0]);
+      let (quotient, remainder) = dividend.div_rem(&divisor).unwrap();
+      assert_eq!(quotient, GeneralPolynomial::new(vec![1.0, 1.0]));
+      assert!(remainder.is_zero());
+    }
+
+    #[test]
+    fn div_rem_zero_divisor() {
+      let dividend = GeneralPolynomial::new(vec![1.0, 1.0]);
+      let divisor = GeneralPolynomial::new(vec![0.0]);
+      assert!(dividend.div_rem(&divisor).is_none());
+    }
+
+    #[test]
+    fn div_rem_i64() {
+      // (x^2 - 5x + 6) / (x - 2) = x - 3 remainder 0
+      let dividend: GeneralPolynomial<i64> = GeneralPolynomial::new(vec![6, -5, 1]);
+      let divisor: GeneralPolynomial<i64> = GeneralPolynomial::new(vec![-2, 1]);
+      let (quotient, remainder) = dividend.div_rem(&divisor).unwrap();
+      assert_eq!(quotient, GeneralPolynomial::new(vec![-3, 1]));
+      assert!(remainder.is_zero());
+    }
+
+    #[test]
+    fn div_rem_i64_inexact_terminates() {
+      // 7x + 5 over the constant 2: integer truncation (7/2 = 3, then
+      // 1/2 = 0) never brings the remainder's degree below the
+      // divisor's, so this only terminates if div_rem detects the lack
+      // of progress instead of trusting exact-zero convergence.
+      let dividend: GeneralPolynomial<i64> = GeneralPolynomial::new(vec![5, 7]);
+      let divisor: GeneralPolynomial<i64> = GeneralPolynomial::new(vec![2]);
+      let (quotient, remainder) = dividend.clone().div_rem(&divisor).unwrap();
+      assert_eq!(quotient * divisor + remainder, dividend);
+    }
+
+    #[test]
+    fn div_rem_f64_inexact_terminates() {
+      // Leading-coefficient cancellation underflows to exactly 0.0
+      // partway through, which used to leave the remainder's degree
+      // stuck forever instead of decreasing.
+      let dividend = GeneralPolynomial::new(vec![7.9885116628428765, 4.3690329659844735]);
+      let divisor = GeneralPolynomial::new(vec![7.726139961580467]);
+      assert!(dividend.div_rem(&divisor).is_some());
+    }
+
+    #[test]
+    fn roots() {
+      // x^2 - 3x + 2 = (x - 1)(x - 2)
+      let gp = GeneralPolynomial::new(vec![2.0, -3.0, 1.0]);
+      let mut roots: Vec<f64> = gp.roots().iter().map(|r| r.re).collect();
+      roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+      assert!((roots[0] - 1.0).abs() < 1e-9);
+      assert!((roots[1] - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn roots_of_zero() {
+      let gp = GeneralPolynomial::new(vec![0.0, 0.0]);
+      assert!(gp.roots().is_empty());
+    }
+
+    #[test]
+    fn from_roots() {
+      let gp = GeneralPolynomial::from_roots(&[1.0, -2.0]);
+      assert_eq!(gp, GeneralPolynomial::new(vec![-2.0, 1.0, 1.0]));
+    }
+
+    #[test]
+    fn from_roots_i64() {
+      let gp: GeneralPolynomial<i64> = GeneralPolynomial::from_roots(&[1, -2]);
+      assert_eq!(gp, GeneralPolynomial::new(vec![-2, 1, 1]));
+    }
+
+    #[test]
+    fn integral() {
+      let gp = GeneralPolynomial::new(vec![3.0, 4.0]);
+      assert_eq!(gp.integral(1.0), GeneralPolynomial::new(vec![1.0, 3.0, 2.0]));
+    }
+
+    #[test]
+    fn definite_integral() {
+      // integral of 2x from 0 to 3 is x^2, so 9 - 0 = 9
+      let gp = GeneralPolynomial::new(vec![0.0, 2.0]);
+      assert_eq!(gp.definite_integral(0.0, 3.0), 9.0);
+    }
+
+    #[test]
+    fn factor_cubic_three_linear() {
+      // (x - 1)(x - 2)(x - 3) has three real roots.
+      let gp = GeneralPolynomial::from_roots(&[1.0, 2.0, 3.0]);
+      match gp.factor_cubic().unwrap() {
+        Factors::ThreeLinear { a, x1, x2, x3 } => {
+          assert!((a - 1.0).abs() < 1e-6);
+          let mut roots = [x1, x2, x3];
+          roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+          assert!((roots[0] - 1.0).abs() < 1e-6);
+          assert!((roots[1] - 2.0).abs() < 1e-6);
+          assert!((roots[2] - 3.0).abs() < 1e-6);
+        }
+        other => panic!("expected ThreeLinear, got {:?}", other),
+      }
+    }
+
+    #[test]
+    fn factor_cubic_linear_and_quadratic() {
+      // x^3 - x^2 + x - 1 = (x - 1)(x^2 + 1), which has no real roots left.
+      let gp = GeneralPolynomial::new(vec![-1.0, 1.0, -1.0, 1.0]);
+      match gp.factor_cubic().unwrap() {
+        Factors::LinearAndQuadratic { a, x1, b, c } => {
+          assert!((a - 1.0).abs() < 1e-6);
+          assert!((x1 - 1.0).abs() < 1e-6);
+          assert!(b.abs() < 1e-6);
+          assert!((c - 1.0).abs() < 1e-6);
+        }
+        other => panic!("expected LinearAndQuadratic, got {:?}", other),
+      }
+    }
+
+    #[test]
+    fn factor_cubic_wrong_degree() {
+      let gp = GeneralPolynomial::new(vec![1.0, 2.0]);
+      assert!(gp.factor_cubic().is_none());
+    }
+  }
+
+  mod factors {
+    use super::Factors;
+
+    #[test]
+    fn three_linear_eq() {
+      let f1 = Factors::ThreeLinear { a: 1.0, x1: 2.0, x2: 3.0, x3: 4.0 };
+      let f2 = Factors::ThreeLinear { a: 1.0, x1: 2.0, x2: 3.0, x3: 4.0 };
+      assert_eq!(f1, f2);
+    }
+
+    #[test]
+    fn linear_and_quadratic_ne() {
+      let three_linear = Factors::ThreeLinear { a: 1.0, x1: 2.0, x2: 3.0, x3: 4.0 };
+      let linear_and_quadratic = Factors::LinearAndQuadratic { a: 1.0, x1: 2.0, b: 1.0, c: 1.0 };
+      assert_ne!(three_linear, linear_and_quadratic);
+    }
   }
-  
+
   mod f64 {
-    use super::Polynomial;
+    use super::{Polynomial, Integrable};
     #[test]
     fn eval() {
       assert_eq!(1.0.evaluate(0.0), 1.0);
@@ -199,8 +833,14 @@ mod tests {
     fn derive() {
       assert_eq!(3.0.derivative(), 0.0);
     }
+
+    #[test]
+    fn integral() {
+      use super::Linear;
+      assert_eq!(3.0.integral(5.0), Linear::new(3.0, 5.0));
+    }
   }
-  
+
   mod linear {
     use super::{Polynomial, Linear};
     #[test]